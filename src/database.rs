@@ -0,0 +1,371 @@
+//! Hardware catalog backing the optimizer's random system search.
+//!
+//! [`Database::new()`] hand-codes the catalog of known processors, memory,
+//! motherboards, and chassis. [`Database::random_system()`] then assembles a
+//! random, compatible combination of these parts into a fully-populated
+//! `System` for scoring by `main`.
+//!
+//! Real hardware can also be pulled in directly via [`import_smbios()`],
+//! which parses a live machine's SMBIOS/DMI tables instead of relying on
+//! the hand-coded catalog below.
+
+mod smbios;
+
+pub use smbios::{import_smbios, SmbiosImport};
+
+use std::collections::BTreeSet;
+
+use rand::seq::SliceRandom;
+
+use crate::{
+    Blade, BladeType, Memory, MemoryType, Motherboard, MotherboardFormFactor,
+    Processor, ProcessorType, System,
+};
+
+/// A catalog of known hardware, used to generate random (but realistic)
+/// `System`s to score.
+pub struct Database {
+    /// All known processors
+    processors: Vec<Processor>,
+
+    /// All known memory DIMMs
+    memory: Vec<Memory>,
+
+    /// All known motherboards, unpopulated (no processors or memory
+    /// installed yet)
+    motherboards: Vec<Motherboard>,
+
+    /// All known chassis, as `System`s with unpopulated blades
+    systems: Vec<System>,
+}
+
+impl Database {
+    /// Hand-code the catalog of hardware we know about
+    pub fn new() -> Self {
+        let processors = vec![
+            Processor {
+                manufacturer:      "Intel".into(),
+                name:              "Xeon Platinum 8280".into(),
+                price:             10009.,
+                clock_rate:        2.7,
+                turbo_rate:        Some(4.0),
+                avx512_rate:       Some(2.3),
+                avx512_turbo_rate: Some(3.3),
+                cores:             28,
+                threads:           56,
+                avx512_fma_units:  Some(2),
+                typ:               ProcessorType::XeonScalableV2_FCLGA3647,
+                scalability:       8,
+                mem_support:       MemoryType::DDR4_2933,
+                mem_channels:      6,
+                l1_per_core:       64  * 1024,
+                l2_per_core:       1024 * 1024,
+                l3:                38_500_000,
+                tdp_watts:         205,
+            },
+            Processor {
+                manufacturer:      "Intel".into(),
+                name:              "Xeon Gold 6258R".into(),
+                price:             3950.,
+                clock_rate:        2.7,
+                turbo_rate:        Some(4.0),
+                avx512_rate:       Some(2.0),
+                avx512_turbo_rate: Some(3.0),
+                cores:             28,
+                threads:           56,
+                avx512_fma_units:  Some(2),
+                typ:               ProcessorType::XeonScalableV2_FCLGA3647,
+                scalability:       4,
+                mem_support:       MemoryType::DDR4_2933,
+                mem_channels:      6,
+                l1_per_core:       64  * 1024,
+                l2_per_core:       1024 * 1024,
+                l3:                38_500_000,
+                tdp_watts:         205,
+            },
+            Processor {
+                manufacturer:      "Intel".into(),
+                name:              "Xeon Gold 6152".into(),
+                price:             3655.,
+                clock_rate:        2.1,
+                turbo_rate:        Some(3.7),
+                avx512_rate:       Some(1.4),
+                avx512_turbo_rate: Some(2.1),
+                cores:             22,
+                threads:           44,
+                avx512_fma_units:  Some(2),
+                typ:               ProcessorType::XeonScalable_FCLGA3647,
+                scalability:       4,
+                mem_support:       MemoryType::DDR4_2667,
+                mem_channels:      6,
+                l1_per_core:       64  * 1024,
+                l2_per_core:       1024 * 1024,
+                l3:                30_250_000,
+                tdp_watts:         140,
+            },
+            Processor {
+                manufacturer:      "Intel".into(),
+                name:              "Xeon W-3175X".into(),
+                price:             2999.,
+                clock_rate:        3.1,
+                turbo_rate:        Some(4.3),
+                avx512_rate:       Some(2.5),
+                avx512_turbo_rate: Some(3.5),
+                cores:             28,
+                threads:           56,
+                avx512_fma_units:  Some(2),
+                typ:               ProcessorType::XeonW_FCLGA3647,
+                scalability:       1,
+                mem_support:       MemoryType::DDR4_2933,
+                mem_channels:      6,
+                l1_per_core:       64  * 1024,
+                l2_per_core:       1024 * 1024,
+                l3:                38_500_000,
+                tdp_watts:         255,
+            },
+            Processor {
+                manufacturer:      "Intel".into(),
+                name:              "Xeon D-2183IT".into(),
+                price:             1222.,
+                clock_rate:        2.2,
+                turbo_rate:        Some(3.0),
+                avx512_rate:       None,
+                avx512_turbo_rate: None,
+                cores:             16,
+                threads:           32,
+                avx512_fma_units:  None,
+                typ:               ProcessorType::XeonD_FCBGA2518,
+                scalability:       1,
+                mem_support:       MemoryType::DDR4_2400,
+                mem_channels:      2,
+                l1_per_core:       64  * 1024,
+                l2_per_core:       256 * 1024,
+                l3:                22_000_000,
+                tdp_watts:         80,
+            },
+        ];
+
+        let memory = vec![
+            Memory {
+                manufacturer: "Samsung".into(),
+                name:         "M393A4K40CB2-CVF".into(),
+                price:        279.,
+                typ:          MemoryType::DDR4_2933,
+                size:         32 * 1024 * 1024 * 1024,
+            },
+            Memory {
+                manufacturer: "Samsung".into(),
+                name:         "M393A8G40MB2-CVF".into(),
+                price:        549.,
+                typ:          MemoryType::DDR4_2933,
+                size:         64 * 1024 * 1024 * 1024,
+            },
+            Memory {
+                manufacturer: "Micron".into(),
+                name:         "MTA18ASF2G72PDZ-2G6".into(),
+                price:        119.,
+                typ:          MemoryType::DDR4_2667,
+                size:         16 * 1024 * 1024 * 1024,
+            },
+            Memory {
+                manufacturer: "Micron".into(),
+                name:         "MTA9ASF1G72AZ-2G3".into(),
+                price:        59.,
+                typ:          MemoryType::DDR4_2400,
+                size:         8 * 1024 * 1024 * 1024,
+            },
+        ];
+
+        let motherboards = vec![
+            // Single-socket, 6 memory channels, 1 DIMM per channel
+            Motherboard {
+                manufacturer:      "Supermicro".into(),
+                name:              "X11SPi-TF".into(),
+                price:             479.,
+                form_factor:       MotherboardFormFactor::B11SRE,
+                proc_support:      ProcessorType::XeonScalableV2_FCLGA3647,
+                scalability:       1,
+                memory_sockets:    6,
+                dimms_per_channel: 1,
+                processors:        Vec::new(),
+                memory:            Vec::new(),
+            },
+            // Single-socket, 6 memory channels, 2 DIMMs per channel
+            Motherboard {
+                manufacturer:      "Supermicro".into(),
+                name:              "X11SPi-TPF".into(),
+                price:             599.,
+                form_factor:       MotherboardFormFactor::B11SPE,
+                proc_support:      ProcessorType::XeonScalableV2_FCLGA3647,
+                scalability:       1,
+                memory_sockets:    12,
+                dimms_per_channel: 2,
+                processors:        Vec::new(),
+                memory:            Vec::new(),
+            },
+            // Dual-socket, 6 memory channels per socket, 1 DIMM per channel
+            Motherboard {
+                manufacturer:      "Supermicro".into(),
+                name:              "X11DPi-NT".into(),
+                price:             699.,
+                form_factor:       MotherboardFormFactor::B11DPE,
+                proc_support:      ProcessorType::XeonScalableV2_FCLGA3647,
+                scalability:       2,
+                memory_sockets:    12,
+                dimms_per_channel: 1,
+                processors:        Vec::new(),
+                memory:            Vec::new(),
+            },
+            // Quad-socket, 6 memory channels per socket, 1 DIMM per channel
+            Motherboard {
+                manufacturer:      "Supermicro".into(),
+                name:              "X11QPH+".into(),
+                price:             3999.,
+                form_factor:       MotherboardFormFactor::X11QPHp,
+                proc_support:      ProcessorType::XeonScalableV2_FCLGA3647,
+                scalability:       4,
+                memory_sockets:    24,
+                dimms_per_channel: 1,
+                processors:        Vec::new(),
+                memory:            Vec::new(),
+            },
+            // Single-socket Xeon W workstation board
+            Motherboard {
+                manufacturer:      "Supermicro".into(),
+                name:              "X11OPi-TF".into(),
+                price:             899.,
+                form_factor:       MotherboardFormFactor::X11OPi,
+                proc_support:      ProcessorType::XeonW_FCLGA3647,
+                scalability:       1,
+                memory_sockets:    8,
+                dimms_per_channel: 2,
+                processors:        Vec::new(),
+                memory:            Vec::new(),
+            },
+        ];
+
+        // Blade carriers. `motherboard` starts unpopulated; it's filled in
+        // per-instance by `random_system()`.
+        let blade_614e = Blade {
+            manufacturer:    "Supermicro",
+            name:            "SBE-614E",
+            price:           799.,
+            blade_type:      BladeType::SBE614E,
+            mb_form_factor:  [
+                MotherboardFormFactor::B11SRE,
+                MotherboardFormFactor::B11SPE,
+            ].iter().cloned().collect::<BTreeSet<_>>(),
+            motherboard:     None,
+            // Shares the chassis-level PSU budget instead
+            power_supply_watts: None,
+        };
+
+        let blade_610j = Blade {
+            manufacturer:    "Supermicro",
+            name:            "SBE-610J",
+            price:           899.,
+            blade_type:      BladeType::SBE610J,
+            mb_form_factor:  [MotherboardFormFactor::B11DPE]
+                .iter().cloned().collect::<BTreeSet<_>>(),
+            motherboard:     None,
+            // Shares the chassis-level PSU budget instead
+            power_supply_watts: None,
+        };
+
+        let blade_standalone = Blade {
+            manufacturer:    "Supermicro",
+            name:            "SYS-1029U",
+            price:           399.,
+            blade_type:      BladeType::None,
+            mb_form_factor:  [
+                MotherboardFormFactor::X11QPHp,
+                MotherboardFormFactor::X11OPi,
+            ].iter().cloned().collect::<BTreeSet<_>>(),
+            motherboard:     None,
+            // Standalone server, carries its own PSU
+            power_supply_watts: Some(800),
+        };
+
+        let systems = vec![
+            // A 14-blade SuperBlade chassis taking single-socket blades
+            System {
+                manufacturer: "Supermicro",
+                name:         "SBE-610 (SBE-614E)",
+                price:        2999.,
+                blade_type:   BladeType::SBE614E,
+                num_blades:   14,
+                blades:       vec![blade_614e; 14],
+                // Shared chassis PSU budget across all 14 blade slots
+                power_supply_watts: Some(8000),
+            },
+            // A 10-blade SuperBlade chassis taking dual-socket blades
+            System {
+                manufacturer: "Supermicro",
+                name:         "SBE-610 (SBE-610J)",
+                price:        2999.,
+                blade_type:   BladeType::SBE610J,
+                num_blades:   10,
+                blades:       vec![blade_610j; 10],
+                // Shared chassis PSU budget across all 10 blade slots
+                power_supply_watts: Some(6000),
+            },
+            // A standalone 1U server (not a blade)
+            System {
+                manufacturer: "Supermicro",
+                name:         "SYS-1029U",
+                price:        0.,
+                blade_type:   BladeType::None,
+                num_blades:   1,
+                blades:       vec![blade_standalone; 1],
+                // Power is budgeted per-blade for standalone servers
+                power_supply_watts: None,
+            },
+        ];
+
+        Database { processors, memory, motherboards, systems }
+    }
+
+    /// Pick a random, fully-populated `System` from the catalog, or `None`
+    /// if no compatible combination of parts could be assembled this time
+    pub fn random_system(&self) -> Option<System> {
+        let mut rng = rand::thread_rng();
+
+        let mut system = self.systems.choose(&mut rng)?.clone();
+
+        for blade in &mut system.blades {
+            // Find a motherboard compatible with this blade's form factor
+            let motherboard = self.motherboards.iter()
+                .filter(|x| blade.mb_form_factor.contains(&x.form_factor))
+                .collect::<Vec<_>>();
+            let mut motherboard = (*motherboard.choose(&mut rng)?).clone();
+
+            // Pick a single processor model compatible with the
+            // motherboard's socket, and populate every socket with it
+            let compat_procs = self.processors.iter()
+                .filter(|x| x.typ == motherboard.proc_support)
+                .collect::<Vec<_>>();
+            let processor = (*compat_procs.choose(&mut rng)?).clone();
+            motherboard.processors =
+                vec![processor.clone(); motherboard.scalability as usize];
+
+            // Pick a single DIMM model that the processors can run at their
+            // rated speed (or slower), and populate every memory socket
+            let compat_mem = self.memory.iter()
+                .filter(|x| x.typ <= processor.mem_support)
+                .collect::<Vec<_>>();
+            let dimm = (*compat_mem.choose(&mut rng)?).clone();
+            motherboard.memory =
+                vec![dimm; motherboard.memory_sockets as usize];
+
+            blade.motherboard = Some(motherboard);
+        }
+
+        Some(system)
+    }
+}
+
+impl Default for Database {
+    fn default() -> Self {
+        Self::new()
+    }
+}
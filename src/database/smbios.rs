@@ -0,0 +1,560 @@
+//! Import real hardware inventory from the running machine's SMBIOS/DMI
+//! tables, as an alternative to the hand-coded catalog in the parent
+//! module.
+//!
+//! This is a minimal walker for the subset of the SMBIOS structure table
+//! this simulator cares about: Type 4 (Processor Information), Type 7
+//! (Cache Information, for the processors' cache sizes), and Type 17
+//! (Memory Device). It does not attempt to parse the rest of the table;
+//! Type 16 (Physical Memory Array) structures are not read.
+
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+
+use crate::{Memory, MemoryType, Motherboard, MotherboardFormFactor,
+    Processor, ProcessorType};
+
+/// Default location of the raw SMBIOS/DMI structure table on Linux
+const DMI_TABLE_PATH: &str = "/sys/firmware/dmi/tables/DMI";
+
+/// Size, in bytes, of a raw SMBIOS structure's header (Type, Length,
+/// Handle). `RawStructure::data` already has this stripped off, so every
+/// spec-absolute field offset below is adjusted by this constant before
+/// indexing into it.
+const STRUCT_HEADER_LEN: usize = 4;
+
+/// Hardware imported from a live machine's SMBIOS tables, as opposed to a
+/// hand-coded `Database` catalog entry
+pub struct SmbiosImport {
+    /// Processors found in the machine's Type 4 structures
+    pub processors: Vec<Processor>,
+
+    /// Populated DIMMs found in the machine's Type 17 structures
+    pub memory: Vec<Memory>,
+
+    /// A `Motherboard` synthesized from the populated Type 17 memory
+    /// devices, with `processors` and `memory` above installed into it so
+    /// the live machine can be scored as a standalone `System`. Its
+    /// `memory_sockets` is the number of populated DIMMs: empty sockets are
+    /// not counted, and DIMMs are not grouped by Type 16 memory array.
+    pub motherboard: Motherboard,
+}
+
+/// One raw SMBIOS structure: its header fields plus the formatted area and
+/// trailing string table, still in raw byte form
+struct RawStructure<'a> {
+    /// Structure type (e.g. 4 for Processor Information)
+    typ: u8,
+
+    /// Structure handle, used to cross-reference structures (e.g. a Type 4
+    /// processor's cache handles point at Type 7 structures by handle)
+    handle: u16,
+
+    /// Formatted area, not including the 4-byte header
+    data: &'a [u8],
+
+    /// Unparsed strings from the string-reference table that follows the
+    /// formatted area, in order (string reference `1` is `strings[0]`)
+    strings: Vec<&'a str>,
+}
+
+impl<'a> RawStructure<'a> {
+    /// Look up a string-reference field. SMBIOS uses `0` to mean "no
+    /// string"; references are otherwise 1-based.
+    fn string(&self, reference: u8) -> Option<&'a str> {
+        if reference == 0 {
+            return None;
+        }
+
+        self.strings.get(reference as usize - 1).copied()
+    }
+
+    /// Read a little-endian byte out of the formatted area
+    fn u8(&self, offset: usize) -> Option<u8> {
+        self.data.get(offset).copied()
+    }
+
+    /// Read a little-endian word out of the formatted area
+    fn u16(&self, offset: usize) -> Option<u16> {
+        let bytes = self.data.get(offset..offset + 2)?;
+        Some(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    /// Read a little-endian dword out of the formatted area
+    fn u32(&self, offset: usize) -> Option<u32> {
+        let bytes = self.data.get(offset..offset + 4)?;
+        Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+}
+
+/// Walk a raw SMBIOS structure table, splitting it into individual
+/// structures. Stops at the Type 127 (End-of-Table) structure or when the
+/// buffer runs out.
+fn parse_structures(raw: &[u8]) -> Vec<RawStructure<'_>> {
+    let mut structures = Vec::new();
+    let mut off = 0usize;
+
+    while off + 4 <= raw.len() {
+        let typ    = raw[off];
+        let length = raw[off + 1] as usize;
+        let handle = u16::from_le_bytes([raw[off + 2], raw[off + 3]]);
+
+        // End-of-table structure, nothing more to parse
+        if typ == 127 {
+            break;
+        }
+
+        // A malformed length would otherwise spin us past the buffer
+        if length < 4 || off + length > raw.len() {
+            break;
+        }
+
+        let data = &raw[off + 4..off + length];
+
+        // The string table immediately follows the formatted area, and is
+        // a sequence of NUL-terminated strings, terminated by a second NUL
+        // byte (i.e. an empty string)
+        let mut strings = Vec::new();
+        let mut str_off = off + length;
+        loop {
+            let start = str_off;
+            while str_off < raw.len() && raw[str_off] != 0 {
+                str_off += 1;
+            }
+
+            if str_off >= raw.len() {
+                break;
+            }
+
+            if str_off == start {
+                // Empty string: end of this structure's string table
+                str_off += 1;
+                break;
+            }
+
+            if let Ok(s) = std::str::from_utf8(&raw[start..str_off]) {
+                strings.push(s);
+            }
+
+            str_off += 1;
+        }
+
+        structures.push(RawStructure { typ, handle, data, strings });
+        off = str_off;
+    }
+
+    structures
+}
+
+/// Best-effort mapping from a processor's SMBIOS "Processor Upgrade"
+/// (socket) byte and version string to one of our known `ProcessorType`s.
+/// SMBIOS doesn't give us a clean 1:1 socket-to-part mapping for every
+/// part we model (Xeon Scalable, Xeon Scalable V2, and Xeon W-3000 all
+/// report Socket LGA3647, and Xeon D has no dedicated socket code at all),
+/// so this falls back to substring-matching the version string.
+fn classify_processor_type(upgrade: u8, version: &str) -> Option<ProcessorType> {
+    match upgrade {
+        // Socket LGA2066
+        0x40 => return Some(ProcessorType::XeonW_FCLGA2066),
+
+        // Socket LGA3647-1, shared by Scalable, Scalable V2, and Xeon W
+        0x3D | 0x3E => {
+            if version.contains("W-") {
+                return Some(ProcessorType::XeonW_FCLGA3647);
+            }
+
+            // Scalable model numbers are four digits with an optional
+            // letter suffix, and the second digit is the generation: "6152"
+            // is 1st-generation, "8280" and "6258R" are 2nd-generation.
+            let second_gen = version.split_whitespace()
+                .map(|w| w.trim_end_matches(|c: char| c.is_ascii_alphabetic()))
+                .find(|w| w.len() == 4 && w.bytes().all(|b| b.is_ascii_digit()))
+                .map_or(false, |model| model.as_bytes()[1] == b'2');
+            if second_gen {
+                return Some(ProcessorType::XeonScalableV2_FCLGA3647);
+            }
+
+            return Some(ProcessorType::XeonScalable_FCLGA3647);
+        }
+
+        _ => {}
+    }
+
+    // Xeon D has no dedicated SMBIOS socket code; key off the version
+    // string's "D-xxxx" model number instead
+    if version.contains("D-") {
+        return Some(ProcessorType::XeonD_FCBGA2518);
+    }
+
+    None
+}
+
+/// Installed size of a Type 7 (Cache Information) structure, in bytes.
+/// Prefers the 32-bit "Installed Cache Size 2" field when the legacy 16-bit
+/// field is saturated (`0xFFFF`: 64 KiB granularity with every size bit
+/// set), since that's the only way to represent caches of 2 GiB or more.
+fn cache_installed_bytes(s: &RawStructure) -> Option<u64> {
+    // Spec-absolute offsets, adjusted by STRUCT_HEADER_LEN; see the
+    // comment in `parse_processor()`.
+    let size = s.u16(0x09 - STRUCT_HEADER_LEN)?;
+
+    if size != 0xFFFF {
+        // Bit 15 set means the granularity is 64 KiB, otherwise 1 KiB
+        let granularity = if size & 0x8000 != 0 { 64 * 1024 } else { 1024 };
+        return Some((size & 0x7FFF) as u64 * granularity);
+    }
+
+    let size2 = s.u32(0x17 - STRUCT_HEADER_LEN)?;
+    let granularity = if size2 & 0x8000_0000 != 0 { 64 * 1024 } else { 1024 };
+    Some((size2 & 0x7FFF_FFFF) as u64 * granularity)
+}
+
+/// Build a map of structure handle -> installed bytes from
+/// every Type 7 (Cache Information) structure, so `parse_processor()` can
+/// resolve the L1/L2/L3 Cache Handle fields a Type 4 structure points at
+fn parse_cache_map(structures: &[RawStructure]) -> HashMap<u16, u64> {
+    structures.iter()
+        .filter(|s| s.typ == 7)
+        .filter_map(|s| Some((s.handle, cache_installed_bytes(s)?)))
+        .collect()
+}
+
+/// Parse a Type 4 (Processor Information) structure into a `Processor`, if
+/// we have enough information to classify and size it
+fn parse_processor(s: &RawStructure, cache: &HashMap<u16, u64>) -> Option<Processor> {
+    // Offsets below are spec-absolute (as documented in the SMBIOS spec,
+    // which counts from the structure's header), so every offset is
+    // adjusted by `STRUCT_HEADER_LEN` before indexing into `data`.
+    let manufacturer = s.string(s.u8(0x07 - STRUCT_HEADER_LEN)?).unwrap_or("Unknown").to_string();
+    let version      = s.string(s.u8(0x10 - STRUCT_HEADER_LEN)?).unwrap_or("Unknown").to_string();
+
+    let upgrade = s.u8(0x19 - STRUCT_HEADER_LEN)?;
+    let typ = classify_processor_type(upgrade, &version)?;
+
+    // Current Speed of `0` means the BIOS didn't populate the field; we
+    // can't score a processor we don't know the clock rate of
+    let clock_rate = s.u16(0x16 - STRUCT_HEADER_LEN)?;
+    if clock_rate == 0 {
+        return None;
+    }
+
+    let max_speed = s.u16(0x14 - STRUCT_HEADER_LEN).unwrap_or(0);
+    let turbo_rate = if max_speed == 0 {
+        None
+    } else {
+        Some(max_speed as f64 / 1000.)
+    };
+
+    // Prefer the extended (2-byte) core/thread counts when present, since
+    // the legacy 1-byte fields saturate at 255
+    let core_count = match s.u16(0x2A - STRUCT_HEADER_LEN) {
+        Some(n) if n != 0 => n as u32,
+        _ => s.u8(0x23 - STRUCT_HEADER_LEN)? as u32,
+    };
+    let threads = match s.u16(0x2E - STRUCT_HEADER_LEN) {
+        Some(n) if n != 0 => n as u32,
+        _ => s.u8(0x25 - STRUCT_HEADER_LEN)? as u32,
+    };
+
+    // Core Enabled (`0`/unset means "unknown", not "zero cores") caps how
+    // many of the socket's cores the BIOS has actually turned on; a core
+    // disabled for licensing or power-capping reasons can't contribute
+    // FLOPS, so don't just report the die's physical Core Count
+    let core_enabled = match s.u16(0x2C - STRUCT_HEADER_LEN) {
+        Some(n) if n != 0 => Some(n as u32),
+        _ => s.u8(0x24 - STRUCT_HEADER_LEN).filter(|&n| n != 0).map(|n| n as u32),
+    };
+    let cores = match core_enabled {
+        Some(enabled) => core_count.min(enabled),
+        None => core_count,
+    };
+
+    // SMBIOS doesn't expose per-ISA clock rates or FMA unit counts; those
+    // are left unset for the caller to fill in by hand if desired.
+    let (mem_support, mem_channels, scalability) = platform_defaults(&typ);
+
+    // Cache Handle fields of `0xFFFF` mean "not provided"; leave the size
+    // at 0 rather than guessing
+    let l1_per_core = s.u16(0x1A - STRUCT_HEADER_LEN).filter(|&h| h != 0xFFFF)
+        .and_then(|h| cache.get(&h)).copied().unwrap_or(0);
+    let l2_per_core = s.u16(0x1C - STRUCT_HEADER_LEN).filter(|&h| h != 0xFFFF)
+        .and_then(|h| cache.get(&h)).copied().unwrap_or(0);
+    let l3 = s.u16(0x1E - STRUCT_HEADER_LEN).filter(|&h| h != 0xFFFF)
+        .and_then(|h| cache.get(&h)).copied().unwrap_or(0);
+
+    // SMBIOS doesn't expose a usable power/thermal envelope directly
+    // (Voltage is nominal core voltage, not power); fall back to a nominal
+    // TDP keyed off the best-effort `ProcessorType` classification
+    let tdp_watts = nominal_tdp_watts(&typ);
+
+    Some(Processor {
+        manufacturer:      manufacturer.into(),
+        name:               version.into(),
+        price:              0.,
+        clock_rate:         clock_rate as f64 / 1000.,
+        turbo_rate,
+        avx512_rate:        None,
+        avx512_turbo_rate:  None,
+        cores,
+        threads,
+        avx512_fma_units:   None,
+        typ,
+        scalability,
+        mem_support,
+        mem_channels,
+        l1_per_core,
+        l2_per_core,
+        l3,
+        tdp_watts,
+    })
+}
+
+/// Nominal TDP, in watts, keyed off the best-effort `ProcessorType`
+/// classification. A real per-part power envelope needs the family/SKU
+/// tables Intel ships separately from SMBIOS; this is a coarse stand-in.
+fn nominal_tdp_watts(typ: &ProcessorType) -> u32 {
+    match typ {
+        ProcessorType::XeonScalableV2_FCLGA3647 => 205,
+        ProcessorType::XeonScalable_FCLGA3647    => 165,
+        ProcessorType::XeonW_FCLGA3647           => 255,
+        ProcessorType::XeonW_FCLGA2066           => 165,
+        ProcessorType::XeonD_FCBGA2518           => 80,
+    }
+}
+
+/// SMBIOS Type 4 only describes the processor die itself; channel count,
+/// supported memory speed, and socket scalability are platform properties
+/// that live in the chipset, not the CPU. We key off the best-effort
+/// `ProcessorType` classification to fill in reasonable defaults.
+fn platform_defaults(typ: &ProcessorType) -> (MemoryType, u8, u8) {
+    match typ {
+        ProcessorType::XeonScalableV2_FCLGA3647 =>
+            (MemoryType::DDR4_2933, 6, 8),
+        ProcessorType::XeonScalable_FCLGA3647 =>
+            (MemoryType::DDR4_2667, 6, 8),
+        ProcessorType::XeonW_FCLGA3647 =>
+            (MemoryType::DDR4_2933, 6, 1),
+        ProcessorType::XeonW_FCLGA2066 =>
+            (MemoryType::DDR4_2667, 4, 1),
+        ProcessorType::XeonD_FCBGA2518 =>
+            (MemoryType::DDR4_2400, 2, 1),
+    }
+}
+
+/// Parse a Type 17 (Memory Device) structure into a `Memory`, skipping
+/// unpopulated DIMM sockets ("No Module Installed" reports a size of `0`)
+fn parse_memory_device(s: &RawStructure) -> Option<Memory> {
+    // Spec-absolute offsets, adjusted by STRUCT_HEADER_LEN; see
+    // the comment in `parse_processor()`.
+    let size_field = s.u16(0x0C - STRUCT_HEADER_LEN)?;
+    if size_field == 0 {
+        // Socket is unpopulated
+        return None;
+    }
+
+    let size_mb = if size_field == 0x7FFF {
+        // Oversized DIMM, real size lives in the extended field
+        s.u32(0x1C - STRUCT_HEADER_LEN)? as u64
+    } else {
+        (size_field & 0x7FFF) as u64
+    };
+
+    let speed = s.u16(0x20 - STRUCT_HEADER_LEN).filter(|&v| v != 0)
+        .or_else(|| s.u16(0x15 - STRUCT_HEADER_LEN).filter(|&v| v != 0))?;
+    let typ = match speed {
+        s if s <= 2133 => MemoryType::DDR4_2133,
+        s if s <= 2400 => MemoryType::DDR4_2400,
+        s if s <= 2667 => MemoryType::DDR4_2667,
+        _              => MemoryType::DDR4_2933,
+    };
+
+    let manufacturer = s.string(s.u8(0x17 - STRUCT_HEADER_LEN)?).unwrap_or("Unknown").to_string();
+    let name = s.string(s.u8(0x1A - STRUCT_HEADER_LEN)?).unwrap_or("Unknown").to_string();
+
+    Some(Memory {
+        manufacturer: manufacturer.into(),
+        name:          name.into(),
+        price:         0.,
+        typ,
+        size:          size_mb * 1024 * 1024,
+    })
+}
+
+/// Parse a raw SMBIOS/DMI structure table blob into a live machine's
+/// hardware inventory
+fn import_smbios_blob(raw: &[u8]) -> SmbiosImport {
+    let structures = parse_structures(raw);
+    let cache = parse_cache_map(&structures);
+
+    let processors: Vec<Processor> = structures.iter()
+        .filter(|s| s.typ == 4)
+        .filter_map(|s| parse_processor(s, &cache))
+        .collect();
+
+    let memory: Vec<Memory> = structures.iter()
+        .filter(|s| s.typ == 17)
+        .filter_map(parse_memory_device)
+        .collect();
+
+    let first_proc = processors.first();
+
+    let motherboard = Motherboard {
+        manufacturer:      "Unknown".into(),
+        name:              "Imported machine".into(),
+        price:             0.,
+        form_factor:       MotherboardFormFactor::Unknown,
+        proc_support:      first_proc.map(|p| p.typ.clone())
+            .unwrap_or(ProcessorType::XeonD_FCBGA2518),
+        scalability:       processors.len() as u8,
+        memory_sockets:    memory.len() as u8,
+        dimms_per_channel: 1,
+        processors:        processors.clone(),
+        memory:            memory.clone(),
+    };
+
+    SmbiosImport { processors, memory, motherboard }
+}
+
+/// Read and parse the live machine's SMBIOS/DMI tables from
+/// `/sys/firmware/dmi/tables/DMI` (Linux) into a `SmbiosImport`.
+pub fn import_smbios() -> io::Result<SmbiosImport> {
+    import_smbios_from(Path::new(DMI_TABLE_PATH))
+}
+
+/// Same as `import_smbios()`, but reads the raw table from an arbitrary
+/// path instead of the standard Linux location
+pub fn import_smbios_from(path: &Path) -> io::Result<SmbiosImport> {
+    let raw = std::fs::read(path)?;
+    Ok(import_smbios_blob(&raw))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build the raw bytes of one SMBIOS structure: a 4-byte header, the
+    /// formatted area, and its trailing string table
+    fn structure(typ: u8, handle: u16, formatted: &[u8], strings: &[&str]) -> Vec<u8> {
+        let mut raw = vec![typ, (4 + formatted.len()) as u8];
+        raw.extend_from_slice(&handle.to_le_bytes());
+        raw.extend_from_slice(formatted);
+
+        for s in strings {
+            raw.extend_from_slice(s.as_bytes());
+            raw.push(0);
+        }
+        raw.push(0); // empty string terminates the string table
+
+        raw
+    }
+
+    /// A byte-accurate synthetic DMI blob: one Type 4 (Processor
+    /// Information) structure referencing one Type 7 (Cache Information)
+    /// structure for its L3, one Type 17 (Memory Device) structure, and
+    /// the Type 127 End-of-Table marker. Field offsets below are spec-
+    /// absolute, matching how a real BIOS lays out these structures.
+    fn synthetic_dmi_table() -> Vec<u8> {
+        let mut processor = vec![0u8; 0x2C]; // formatted area: spec 0x04..0x30
+        processor[0x04 - STRUCT_HEADER_LEN] = 1;             // Socket Designation (string)
+        processor[0x07 - STRUCT_HEADER_LEN] = 2;             // Processor Manufacturer (string)
+        processor[0x10 - STRUCT_HEADER_LEN] = 3;             // Processor Version (string)
+        processor[0x14 - STRUCT_HEADER_LEN..0x16 - STRUCT_HEADER_LEN].copy_from_slice(&4000u16.to_le_bytes()); // Max Speed
+        processor[0x16 - STRUCT_HEADER_LEN..0x18 - STRUCT_HEADER_LEN].copy_from_slice(&2700u16.to_le_bytes()); // Current Speed
+        processor[0x19 - STRUCT_HEADER_LEN] = 0x3E;          // Processor Upgrade (Socket LGA3647-1)
+        processor[0x1A - STRUCT_HEADER_LEN..0x1C - STRUCT_HEADER_LEN].copy_from_slice(&0xFFFFu16.to_le_bytes()); // L1 Cache Handle
+        processor[0x1C - STRUCT_HEADER_LEN..0x1E - STRUCT_HEADER_LEN].copy_from_slice(&0xFFFFu16.to_le_bytes()); // L2 Cache Handle
+        processor[0x1E - STRUCT_HEADER_LEN..0x20 - STRUCT_HEADER_LEN].copy_from_slice(&5u16.to_le_bytes());     // L3 Cache Handle
+        processor[0x23 - STRUCT_HEADER_LEN] = 28;            // Core Count
+        processor[0x25 - STRUCT_HEADER_LEN] = 56;            // Thread Count
+        processor[0x2A - STRUCT_HEADER_LEN..0x2C - STRUCT_HEADER_LEN].copy_from_slice(&28u16.to_le_bytes());    // Core Count 2
+        processor[0x2E - STRUCT_HEADER_LEN..0x30 - STRUCT_HEADER_LEN].copy_from_slice(&56u16.to_le_bytes());    // Thread Count 2
+
+        let mut cache = vec![0u8; 8]; // formatted area: spec 0x04..0x0C
+        cache[0x09 - STRUCT_HEADER_LEN..0x0B - STRUCT_HEADER_LEN].copy_from_slice(&16384u16.to_le_bytes()); // Installed Size (1 KiB units)
+
+        let mut memory = vec![0u8; 0x24]; // formatted area: spec 0x04..0x28
+        memory[0x0C - STRUCT_HEADER_LEN..0x0E - STRUCT_HEADER_LEN].copy_from_slice(&0x7FFFu16.to_le_bytes()); // Size (oversized marker)
+        memory[0x17 - STRUCT_HEADER_LEN] = 2;             // Manufacturer (string)
+        memory[0x1A - STRUCT_HEADER_LEN] = 3;             // Part Number (string)
+        memory[0x1C - STRUCT_HEADER_LEN..0x20 - STRUCT_HEADER_LEN].copy_from_slice(&32768u32.to_le_bytes()); // Extended Size (MiB)
+        memory[0x20 - STRUCT_HEADER_LEN..0x22 - STRUCT_HEADER_LEN].copy_from_slice(&2933u16.to_le_bytes());  // Configured Memory Clock Speed
+
+        let mut raw = Vec::new();
+        raw.extend(structure(4, 1, &processor, &["CPU1", "Intel", "Xeon Gold 6258R"]));
+        raw.extend(structure(7, 5, &cache, &[]));
+        raw.extend(structure(17, 0x11, &memory, &["DIMM_A1", "Samsung", "M393A4K40CB2-CVF"]));
+        raw.extend_from_slice(&[127, 4, 0, 0]); // End-of-Table
+
+        raw
+    }
+
+    #[test]
+    fn parses_spec_compliant_processor_and_memory_device() {
+        let import = import_smbios_blob(&synthetic_dmi_table());
+
+        assert_eq!(import.processors.len(), 1);
+        let processor = &import.processors[0];
+        assert_eq!(&*processor.manufacturer, "Intel");
+        assert_eq!(&*processor.name, "Xeon Gold 6258R");
+        assert_eq!(processor.typ, ProcessorType::XeonScalableV2_FCLGA3647);
+        assert_eq!(processor.clock_rate, 2.7);
+        assert_eq!(processor.cores, 28);
+        assert_eq!(processor.threads, 56);
+        assert_eq!(processor.l3, 16 * 1024 * 1024);
+
+        assert_eq!(import.memory.len(), 1);
+        let dimm = &import.memory[0];
+        assert_eq!(&*dimm.manufacturer, "Samsung");
+        assert_eq!(&*dimm.name, "M393A4K40CB2-CVF");
+        assert_eq!(dimm.typ, MemoryType::DDR4_2933);
+        assert_eq!(dimm.size, 32768 * 1024 * 1024);
+    }
+
+    #[test]
+    fn falls_back_to_legacy_speed_field_when_configured_speed_is_unset() {
+        // Configured Memory Clock Speed (0x20) left at 0 means the BIOS
+        // didn't populate it; parse_memory_device() should fall back to
+        // the legacy Speed field at 0x15 instead.
+        let mut memory = vec![0u8; 0x24]; // formatted area: spec 0x04..0x28
+        memory[0x0C - STRUCT_HEADER_LEN..0x0E - STRUCT_HEADER_LEN].copy_from_slice(&4096u16.to_le_bytes()); // Size (4096 MB)
+        memory[0x15 - STRUCT_HEADER_LEN..0x17 - STRUCT_HEADER_LEN].copy_from_slice(&2400u16.to_le_bytes()); // Speed (legacy)
+        memory[0x17 - STRUCT_HEADER_LEN] = 1;                                              // Manufacturer (string)
+
+        let mut raw = Vec::new();
+        raw.extend(structure(17, 0x11, &memory, &["Micron"]));
+        raw.extend_from_slice(&[127, 4, 0, 0]); // End-of-Table
+
+        let import = import_smbios_blob(&raw);
+
+        assert_eq!(import.memory.len(), 1);
+        assert_eq!(import.memory[0].typ, MemoryType::DDR4_2400);
+    }
+
+    #[test]
+    fn clamps_cores_to_core_enabled_when_bios_disabled_some() {
+        // A 32-core die with 16 cores disabled (licensing/power-capping)
+        // should report 16 usable cores, not the physical Core Count.
+        let mut processor = vec![0u8; 0x2C]; // formatted area: spec 0x04..0x30
+        processor[0x07 - STRUCT_HEADER_LEN] = 1;             // Processor Manufacturer (string)
+        processor[0x10 - STRUCT_HEADER_LEN] = 2;             // Processor Version (string)
+        processor[0x16 - STRUCT_HEADER_LEN..0x18 - STRUCT_HEADER_LEN].copy_from_slice(&2700u16.to_le_bytes()); // Current Speed
+        processor[0x19 - STRUCT_HEADER_LEN] = 0x3E;          // Processor Upgrade (Socket LGA3647-1)
+        processor[0x1A - STRUCT_HEADER_LEN..0x1C - STRUCT_HEADER_LEN].copy_from_slice(&0xFFFFu16.to_le_bytes()); // L1 Cache Handle
+        processor[0x1C - STRUCT_HEADER_LEN..0x1E - STRUCT_HEADER_LEN].copy_from_slice(&0xFFFFu16.to_le_bytes()); // L2 Cache Handle
+        processor[0x1E - STRUCT_HEADER_LEN..0x20 - STRUCT_HEADER_LEN].copy_from_slice(&0xFFFFu16.to_le_bytes()); // L3 Cache Handle
+        processor[0x23 - STRUCT_HEADER_LEN] = 32;            // Core Count
+        processor[0x24 - STRUCT_HEADER_LEN] = 16;            // Core Enabled
+        processor[0x25 - STRUCT_HEADER_LEN] = 64;            // Thread Count
+
+        let mut raw = Vec::new();
+        raw.extend(structure(4, 1, &processor, &["Intel", "Xeon Platinum 8280"]));
+        raw.extend_from_slice(&[127, 4, 0, 0]); // End-of-Table
+
+        let import = import_smbios_blob(&raw);
+
+        assert_eq!(import.processors.len(), 1);
+        assert_eq!(import.processors[0].cores, 16);
+        assert_eq!(import.processors[0].threads, 64);
+    }
+}
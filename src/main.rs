@@ -2,6 +2,7 @@
 
 pub mod database;
 
+use std::borrow::Cow;
 use std::collections::BTreeSet;
 
 use database::Database;
@@ -23,11 +24,14 @@ pub enum ProcessorType {
 /// A processor
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct Processor {
-    /// Manufacturer of the hardware
-    manufacturer: &'static str,
+    /// Manufacturer of the hardware. Catalog entries borrow a `'static`
+    /// string, but hardware imported from SMBIOS (see
+    /// `database::import_smbios()`) owns its strings since they're parsed
+    /// out of a runtime table.
+    manufacturer: Cow<'static, str>,
 
     /// Name of the hardware/model number
-    name: &'static str,
+    name: Cow<'static, str>,
 
     /// Price of the processor in USD
     price: f64,
@@ -68,6 +72,18 @@ pub struct Processor {
 
     /// Number of memory channels
     mem_channels: u8,
+
+    /// Private L1 cache per core, in bytes (instruction + data combined)
+    l1_per_core: u64,
+
+    /// Private L2 cache per core, in bytes
+    l2_per_core: u64,
+
+    /// Shared L3 cache for the whole die, in bytes
+    l3: u64,
+
+    /// Thermal Design Power, in watts
+    tdp_watts: u32,
 }
 
 /// Different types of memory
@@ -102,10 +118,10 @@ impl MemoryType {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct Memory {
     /// Manufacturer of the hardware
-    manufacturer: &'static str,
+    manufacturer: Cow<'static, str>,
 
     /// Name of the hardware/model number
-    name: &'static str,
+    name: Cow<'static, str>,
 
     /// Price of the DIMM in USD
     price: f64,
@@ -125,16 +141,21 @@ pub enum MotherboardFormFactor {
     B11SPE,
     B11DPE,
     X11QPHp,
+
+    /// Not a cataloged form factor. Used for motherboards synthesized from
+    /// a live machine's SMBIOS tables (see `database::import_smbios()`),
+    /// which have no known `Blade` chassis to socket into.
+    Unknown,
 }
 
 /// A motherboard
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct Motherboard {
     /// Manufacturer of the hardware
-    manufacturer: &'static str,
+    manufacturer: Cow<'static, str>,
 
     /// Name of the hardware/model number
-    name: &'static str,
+    name: Cow<'static, str>,
 
     /// Price of the motherboard in USD
     price: f64,
@@ -198,6 +219,12 @@ pub struct Blade {
 
     /// Motherboard which has been socketed
     motherboard: Option<Motherboard>,
+
+    /// Power supply capacity for this blade, in watts. Only meaningful for
+    /// standalone blades (`BladeType::None`) that carry their own power
+    /// supply; blades that go into a chassis draw from the chassis-level
+    /// `System::power_supply_watts` budget instead.
+    power_supply_watts: Option<u32>,
 }
 
 /// For blade servers that have multiple `Blade`s, they will go into a
@@ -221,6 +248,47 @@ pub struct System {
 
     /// Blades that have been put into this system
     blades: Vec<Blade>,
+
+    /// Power supply capacity shared across all blades in this chassis, in
+    /// watts. `None` if the system's power envelope isn't modeled (e.g.
+    /// standalone servers, which budget power per-`Blade` instead).
+    power_supply_watts: Option<u32>,
+}
+
+/// One NUMA node: a single socket plus its local share of DIMM capacity and
+/// channels. `Motherboard` doesn't track which socket a DIMM is plugged
+/// into (memory is "uniformly distributed between all of the processors"),
+/// so a node's DIMMs are apportioned round-robin across sockets.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NumaNode {
+    /// Index of the blade this node's motherboard is installed in. NUMA
+    /// locality is only meaningful within a single motherboard, so this is
+    /// what groups nodes for balance comparisons, not the whole `System`.
+    pub blade: usize,
+
+    /// Socket index within the motherboard this node belongs to
+    pub socket: usize,
+
+    /// The processor local to this node
+    pub processor: Processor,
+
+    /// DIMM capacity local to this node, in bytes
+    pub local_bytes: u64,
+
+    /// Number of DIMMs installed local to this node
+    pub local_dimms: u8,
+}
+
+/// Effective DDR4 transfer rate after de-rating for DIMMs-per-channel.
+/// Populating a second DIMM on a channel commonly costs a speed bin (e.g.
+/// DDR4-2933 drops to DDR4-2667 at 2 DIMMs per channel).
+fn derate_mem_speed(mts: u32, dimms_per_channel: u8) -> u32 {
+    const BINS: [u32; 4] = [2133, 2400, 2667, 2933];
+
+    let idx = BINS.iter().rposition(|&bin| bin <= mts).unwrap_or(0);
+    let idx = idx.saturating_sub(dimms_per_channel.saturating_sub(1) as usize);
+
+    BINS[idx]
 }
 
 macro_rules! get_proc_sum {
@@ -317,14 +385,309 @@ impl System {
         acc
     }
 
+    /// Aggregate memory bandwidth across all populated sockets, in GB/s.
+    ///
+    /// Per-socket bandwidth is `channels * effective_MT/s * 8 bytes / 1000`,
+    /// where `effective_MT/s` is the slower of the installed DIMMs' rated
+    /// speed and the processor's supported memory speed, de-rated for
+    /// DIMMs-per-channel. `Motherboard::memory_sockets` is uniformly
+    /// distributed across `scalability` sockets rather than tracked
+    /// per-socket, so a partially-populated board has its bandwidth scaled
+    /// down proportionally to the fraction of sockets actually populated.
+    pub fn mem_bandwidth_gbps(&self) -> f64 {
+        let mut acc = 0f64;
+
+        // Go through each blade in the system
+        for blade in &self.blades {
+            let motherboard = blade.motherboard.as_ref().unwrap();
+
+            if motherboard.memory_sockets == 0 {
+                continue;
+            }
+
+            // The slowest installed DIMM bottlenecks the whole board
+            let rated_mts = match motherboard.memory.iter()
+                    .map(|x| x.typ as u32).min() {
+                Some(mts) => mts,
+                None => continue,
+            };
+
+            let populated_fraction = motherboard.memory.len() as f64
+                / motherboard.memory_sockets as f64;
+
+            let board_gbps = motherboard.processors.iter().fold(0f64, |acc, x| {
+                let mts = derate_mem_speed(rated_mts.min(x.mem_support as u32),
+                    motherboard.dimms_per_channel);
+
+                acc + x.mem_channels as f64 * mts as f64 * 8. / 1000.
+            });
+
+            acc += board_gbps * populated_fraction;
+        }
+
+        acc
+    }
+
+    /// Memory bandwidth per dollar, in GB/s per USD
+    pub fn mem_bandwidth_per_dollar(&self) -> f64 {
+        self.mem_bandwidth_gbps() / self.price()
+    }
+
+    /// Memory bandwidth per core, in GB/s per core. A rough bytes/FLOP
+    /// balance metric: lets memory-bandwidth-bound workloads be ranked
+    /// separately from raw GFLOPS/$ density.
+    pub fn mem_bandwidth_per_core_gbps(&self) -> f64 {
+        self.mem_bandwidth_gbps() / self.cores() as f64
+    }
+
+    /// Fraction (0.0 - 1.0) of the machine's threads whose per-thread share
+    /// of cache (private L2, split evenly among the threads on its core,
+    /// plus shared L3, split evenly among all of a processor's threads) is
+    /// large enough to hold a `bytes_per_thread`-sized working set.
+    ///
+    /// Useful for ranking otherwise-equal GFLOPS/$ builds by how well they
+    /// fit the AVX-512 FMA-heavy kernels this simulator targets, which are
+    /// sensitive to spilling out of cache.
+    pub fn working_set_fit(&self, bytes_per_thread: u64) -> f64 {
+        let mut fitting = 0u64;
+        let mut total = 0u64;
+
+        // Go through each blade in the system
+        for blade in &self.blades {
+            let motherboard = blade.motherboard.as_ref().unwrap();
+
+            for x in &motherboard.processors {
+                total += x.threads as u64;
+
+                let threads_per_core = (x.threads / x.cores.max(1)).max(1) as u64;
+                let l2_per_thread = x.l2_per_core / threads_per_core;
+                let l3_per_thread = x.l3 / x.threads.max(1) as u64;
+
+                if l2_per_thread + l3_per_thread >= bytes_per_thread {
+                    fitting += x.threads as u64;
+                }
+            }
+        }
+
+        if total == 0 { 0. } else { fitting as f64 / total as f64 }
+    }
+
+    /// Total shared L3 cache across all sockets, in bytes
+    pub fn total_l3_bytes(&self) -> u64 {
+        let mut acc = 0u64;
+
+        // Go through each blade in the system
+        for blade in &self.blades {
+            let motherboard = blade.motherboard.as_ref().unwrap();
+            acc += motherboard.processors.iter().fold(0, |acc, x| acc + x.l3);
+        }
+
+        acc
+    }
+
+    /// Total processor TDP across all sockets, in watts
+    pub fn total_tdp_watts(&self) -> u32 {
+        let mut acc = 0u32;
+
+        // Go through each blade in the system
+        for blade in &self.blades {
+            let motherboard = blade.motherboard.as_ref().unwrap();
+            acc += motherboard.processors.iter().fold(0, |acc, x| acc + x.tdp_watts);
+        }
+
+        acc
+    }
+
+    /// Chassis power budget for this system, in watts, or `None` if it
+    /// isn't modeled. Chassis that hold blades share a single
+    /// `power_supply_watts` budget; standalone (non-blade) systems budget
+    /// power per-`Blade` instead, so this sums those if present.
+    pub fn power_budget_watts(&self) -> Option<u32> {
+        if let Some(watts) = self.power_supply_watts {
+            return Some(watts);
+        }
+
+        self.blades.iter()
+            .map(|x| x.power_supply_watts)
+            .collect::<Option<Vec<u32>>>()
+            .map(|watts| watts.iter().sum())
+    }
+
+    /// Turbo AVX-512 GFLOPS per watt of TDP
+    pub fn turbo_gflops_per_watt(&self) -> f64 {
+        self.turbo_sp_float_fma_gflops() / self.total_tdp_watts() as f64
+    }
+
+    /// Group each motherboard's sockets with their local share of DIMM
+    /// capacity, treating the machine as NUMA-aware rather than one flat
+    /// memory pool
+    pub fn numa_nodes(&self) -> Vec<NumaNode> {
+        let mut nodes = Vec::new();
+
+        // Go through each blade in the system
+        for (blade_index, blade) in self.blades.iter().enumerate() {
+            let motherboard = blade.motherboard.as_ref().unwrap();
+
+            let sockets = motherboard.processors.len();
+            if sockets == 0 {
+                continue;
+            }
+
+            let mut local_bytes = vec![0u64; sockets];
+            let mut local_dimms = vec![0u8; sockets];
+
+            // DIMMs aren't tracked per-socket, so apportion them
+            // round-robin across sockets
+            for (ii, dimm) in motherboard.memory.iter().enumerate() {
+                local_bytes[ii % sockets] += dimm.size;
+                local_dimms[ii % sockets] += 1;
+            }
+
+            for (socket, processor) in motherboard.processors.iter().enumerate() {
+                nodes.push(NumaNode {
+                    blade: blade_index,
+                    socket,
+                    processor: processor.clone(),
+                    local_bytes: local_bytes[socket],
+                    local_dimms: local_dimms[socket],
+                });
+            }
+        }
+
+        nodes
+    }
+
+    /// How evenly DIMM capacity is spread across NUMA nodes: `1.0` is
+    /// perfectly balanced, lower when one socket is memory-starved relative
+    /// to the fullest socket *on the same motherboard*. NUMA locality only
+    /// exists within a motherboard, so a multi-blade `System` is scored by
+    /// its worst-balanced blade rather than comparing sockets across
+    /// independently-provisioned blades.
+    pub fn numa_balance(&self) -> f64 {
+        let nodes = self.numa_nodes();
+
+        let mut worst: f64 = 1.;
+
+        for blade_index in 0..self.blades.len() {
+            let blade_nodes = nodes.iter()
+                .filter(|x| x.blade == blade_index)
+                .map(|x| x.local_bytes);
+
+            let max_bytes = blade_nodes.clone().max().unwrap_or(0);
+            if max_bytes == 0 {
+                continue;
+            }
+
+            let min_bytes = blade_nodes.min().unwrap_or(0);
+
+            worst = worst.min(min_bytes as f64 / max_bytes as f64);
+        }
+
+        worst
+    }
+
+    /// Bandwidth of the worst-provisioned NUMA node, in GB/s. A thread
+    /// pinned to a memory-starved node is bottlenecked by this, regardless
+    /// of how much aggregate bandwidth the rest of the machine has.
+    pub fn min_local_bandwidth_gbps(&self) -> f64 {
+        let mut worst = f64::INFINITY;
+
+        // Go through each blade in the system
+        for blade in &self.blades {
+            let motherboard = blade.motherboard.as_ref().unwrap();
+
+            let sockets = motherboard.processors.len();
+            if sockets == 0 {
+                continue;
+            }
+
+            let slots_per_socket =
+                (motherboard.memory_sockets as usize / sockets).max(1);
+
+            let mut node_dimms: Vec<Vec<&Memory>> = vec![Vec::new(); sockets];
+            for (ii, dimm) in motherboard.memory.iter().enumerate() {
+                node_dimms[ii % sockets].push(dimm);
+            }
+
+            for (socket, processor) in motherboard.processors.iter().enumerate() {
+                let dimms = &node_dimms[socket];
+
+                let gbps = match dimms.iter().map(|x| x.typ as u32).min() {
+                    Some(rated_mts) => {
+                        let mts = derate_mem_speed(
+                            rated_mts.min(processor.mem_support as u32),
+                            motherboard.dimms_per_channel);
+                        let fraction =
+                            (dimms.len() as f64 / slots_per_socket as f64).min(1.);
+
+                        processor.mem_channels as f64 * mts as f64 * 8. /
+                            1000. * fraction
+                    }
+                    None => 0.,
+                };
+
+                worst = worst.min(gbps);
+            }
+        }
+
+        if worst.is_finite() { worst } else { 0. }
+    }
+
     get_proc_sum!(cores,      u32, false);
     get_proc_sum!(threads,    u32, false);
     get_proc_sum!(clock_rate, f64, true);
 }
 
+/// Objective the search loop ranks candidate `System`s by. Selected via the
+/// first command-line argument, defaulting to `GflopsPerDollar`.
+enum RankBy {
+    /// Turbo AVX-512 GFLOPS per dollar (the original objective)
+    GflopsPerDollar,
+
+    /// Memory bandwidth (GB/s) per dollar, for memory-bandwidth-bound
+    /// workloads
+    BandwidthPerDollar,
+
+    /// Memory bandwidth (GB/s) per core, to find memory-balanced rather
+    /// than FLOP-dense builds
+    BandwidthPerCore,
+
+    /// Turbo AVX-512 GFLOPS per watt of TDP, for power/cooling-constrained
+    /// datacenters
+    GflopsPerWatt,
+}
+
+impl RankBy {
+    fn from_arg(arg: Option<&str>) -> Self {
+        match arg {
+            Some("bandwidth") => RankBy::BandwidthPerDollar,
+            Some("balance")   => RankBy::BandwidthPerCore,
+            Some("power")     => RankBy::GflopsPerWatt,
+            _                 => RankBy::GflopsPerDollar,
+        }
+    }
+
+    fn score(&self, system: &System) -> f64 {
+        match self {
+            RankBy::GflopsPerDollar =>
+                system.turbo_sp_float_fma_gflops() / system.price(),
+            RankBy::BandwidthPerDollar => system.mem_bandwidth_per_dollar(),
+            RankBy::BandwidthPerCore   => system.mem_bandwidth_per_core_gbps(),
+            RankBy::GflopsPerWatt      => system.turbo_gflops_per_watt(),
+        }
+    }
+}
+
+/// Minimum acceptable `System::numa_balance()`. Configurations that look
+/// good on aggregate FLOPS/$ but would perform badly in practice due to
+/// lopsided DIMM placement across sockets are pruned below this.
+const MIN_NUMA_BALANCE: f64 = 0.5;
+
 fn main() -> serde_json::Result<()> {
     let database = Database::new();
 
+    let rank_by = RankBy::from_arg(std::env::args().nth(1).as_deref());
+
     let mut systems = Vec::new();
 
     loop {
@@ -337,6 +700,20 @@ fn main() -> serde_json::Result<()> {
                         continue;
                     }
 
+                    // Prune configurations that would overdraw their
+                    // chassis' power budget rather than ranking them
+                    if let Some(budget) = system.power_budget_watts() {
+                        if system.total_tdp_watts() > budget {
+                            continue;
+                        }
+                    }
+
+                    // Prune lopsided NUMA configurations that would look
+                    // good on aggregate FLOPS/$ but bottleneck in practice
+                    if system.numa_balance() < MIN_NUMA_BALANCE {
+                        continue;
+                    }
+
                     if !systems.contains(&system) {
                         systems.push(system);
                     }
@@ -345,9 +722,7 @@ fn main() -> serde_json::Result<()> {
         }
 
         systems.sort_by(|x, y| {
-            (x.turbo_sp_float_fma_gflops() / x.price())
-                .partial_cmp(&(y.turbo_sp_float_fma_gflops() / y.price()))
-                .unwrap()
+            rank_by.score(x).partial_cmp(&rank_by.score(y)).unwrap()
         });
 
         systems.drain(..systems.len()-50);
@@ -355,17 +730,288 @@ fn main() -> serde_json::Result<()> {
         eprint!("---\n");
         for (ii, system) in systems.iter().enumerate() {
             let gib = system.ram() as f64 / 1024. / 1024. / 1024.;
-            eprint!("{:3} | {:4}C / {:4}T | {:9.2} base GFLOPS | {:9.2} turbo GFLOPS | {:8.2} GiB | ${:10.2} | {:10.6} base | {:10.6} turbo\n",
+            eprint!("{:3} | {:4}C / {:4}T | {:9.2} base GFLOPS | {:9.2} turbo GFLOPS | {:8.2} GiB | {:8.2} GB/s | {:8.2} min-node GB/s | {:4.2} NUMA bal | {:5} W | ${:10.2} | {:10.6} base | {:10.6} turbo | {:10.6} GB/s/$ | {:10.6} GFLOPS/W\n",
                 ii, system.cores(),
                 system.threads(), system.sp_float_fma_gflops(),
                 system.turbo_sp_float_fma_gflops(),
                 gib,
+                system.mem_bandwidth_gbps(),
+                system.min_local_bandwidth_gbps(),
+                system.numa_balance(),
+                system.total_tdp_watts(),
                 system.price(),
                 system.sp_float_fma_gflops() / system.price(),
-                system.turbo_sp_float_fma_gflops() / system.price());
+                system.turbo_sp_float_fma_gflops() / system.price(),
+                system.mem_bandwidth_per_dollar(),
+                system.turbo_gflops_per_watt());
 
             std::fs::write(format!("best{}.txt", ii), format!("{:#?}\n", system)).unwrap();
         }
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A minimal, realistic-enough processor for exercising `System`'s
+    /// scoring methods without pulling in the full `Database` catalog.
+    fn test_processor() -> Processor {
+        Processor {
+            manufacturer:      "Test".into(),
+            name:              "Test CPU".into(),
+            price:             1000.,
+            clock_rate:        2.0,
+            turbo_rate:        Some(3.0),
+            avx512_rate:       Some(1.8),
+            avx512_turbo_rate: Some(2.5),
+            cores:             8,
+            threads:           16,
+            avx512_fma_units:  Some(2),
+            typ:               ProcessorType::XeonScalableV2_FCLGA3647,
+            scalability:       2,
+            mem_support:       MemoryType::DDR4_2933,
+            mem_channels:      6,
+            l1_per_core:       32   * 1024,
+            l2_per_core:       1024 * 1024,
+            l3:                20_000_000,
+            tdp_watts:         150,
+        }
+    }
+
+    fn test_memory(typ: MemoryType, size: u64) -> Memory {
+        Memory { manufacturer: "Test".into(), name: "Test DIMM".into(), price: 50., typ, size }
+    }
+
+    fn test_motherboard(processors: Vec<Processor>, memory: Vec<Memory>,
+            memory_sockets: u8, dimms_per_channel: u8) -> Motherboard {
+        Motherboard {
+            manufacturer:      "Test".into(),
+            name:              "Test MB".into(),
+            price:             200.,
+            form_factor:       MotherboardFormFactor::Unknown,
+            proc_support:      ProcessorType::XeonScalableV2_FCLGA3647,
+            scalability:       processors.len() as u8,
+            memory_sockets,
+            dimms_per_channel,
+            processors,
+            memory,
+        }
+    }
+
+    fn test_blade(motherboard: Motherboard) -> Blade {
+        Blade {
+            manufacturer:       "Test",
+            name:               "Test Blade",
+            price:              100.,
+            blade_type:         BladeType::None,
+            mb_form_factor:     BTreeSet::new(),
+            motherboard:        Some(motherboard),
+            power_supply_watts: None,
+        }
+    }
+
+    fn test_system(blades: Vec<Blade>, power_supply_watts: Option<u32>) -> System {
+        System {
+            manufacturer: "Test",
+            name:         "Test System",
+            price:        500.,
+            blade_type:   BladeType::None,
+            num_blades:   blades.len() as u8,
+            blades,
+            power_supply_watts,
+        }
+    }
+
+    #[test]
+    fn derate_mem_speed_single_dimm_per_channel_is_unaffected() {
+        assert_eq!(derate_mem_speed(2933, 1), 2933);
+    }
+
+    #[test]
+    fn derate_mem_speed_two_dimms_per_channel_drops_one_bin() {
+        assert_eq!(derate_mem_speed(2933, 2), 2667);
+    }
+
+    #[test]
+    fn derate_mem_speed_clamps_at_the_slowest_bin() {
+        // Only 4 bins exist; de-rating further than the slowest just
+        // clamps there instead of underflowing.
+        assert_eq!(derate_mem_speed(2933, 5), 2133);
+    }
+
+    #[test]
+    fn mem_bandwidth_scales_down_for_a_partially_populated_board() {
+        // 2 of 4 memory sockets populated should halve the board's
+        // bandwidth relative to a fully-populated one.
+        let processor = test_processor();
+        let memory = vec![
+            test_memory(MemoryType::DDR4_2933, 16 * 1024 * 1024 * 1024),
+            test_memory(MemoryType::DDR4_2933, 16 * 1024 * 1024 * 1024),
+        ];
+        let motherboard = test_motherboard(vec![processor], memory, 4, 1);
+        let system = test_system(vec![test_blade(motherboard)], None);
+
+        // 6 channels * 2933 MT/s * 8 bytes / 1000 = 140.784 GB/s fully
+        // populated, scaled by the 2/4 populated fraction.
+        assert!((system.mem_bandwidth_gbps() - 140.784 * 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn mem_bandwidth_is_zero_with_no_dimms_installed() {
+        let motherboard = test_motherboard(vec![test_processor()], vec![], 4, 1);
+        let system = test_system(vec![test_blade(motherboard)], None);
+
+        assert_eq!(system.mem_bandwidth_gbps(), 0.);
+        assert_eq!(system.mem_bandwidth_per_core_gbps(), 0.);
+    }
+
+    #[test]
+    fn mem_bandwidth_per_dollar_divides_by_full_system_price() {
+        let memory = vec![test_memory(MemoryType::DDR4_2933, 16 * 1024 * 1024 * 1024)];
+        let motherboard = test_motherboard(vec![test_processor()], memory, 1, 1);
+        let system = test_system(vec![test_blade(motherboard)], None);
+
+        assert!((system.mem_bandwidth_per_dollar()
+            - system.mem_bandwidth_gbps() / system.price()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn working_set_fit_counts_threads_whose_cache_share_is_big_enough() {
+        // 1 MiB L2 per core (2 threads/core -> 512 KiB/thread) + 20 MB L3
+        // over 16 threads (1.25 MB/thread) comfortably covers a 1 MB
+        // working set, but not a 32 MB one.
+        let motherboard = test_motherboard(vec![test_processor()], vec![], 0, 1);
+        let system = test_system(vec![test_blade(motherboard)], None);
+
+        assert_eq!(system.working_set_fit(1024 * 1024), 1.0);
+        assert_eq!(system.working_set_fit(32 * 1024 * 1024), 0.0);
+    }
+
+    #[test]
+    fn total_l3_bytes_sums_across_all_sockets() {
+        let motherboard = test_motherboard(
+            vec![test_processor(), test_processor()], vec![], 0, 1);
+        let system = test_system(vec![test_blade(motherboard)], None);
+
+        assert_eq!(system.total_l3_bytes(), 2 * 20_000_000);
+    }
+
+    #[test]
+    fn total_tdp_and_gflops_per_watt_track_installed_sockets() {
+        let motherboard = test_motherboard(
+            vec![test_processor(), test_processor()], vec![], 0, 1);
+        let system = test_system(vec![test_blade(motherboard)], None);
+
+        assert_eq!(system.total_tdp_watts(), 2 * 150);
+        assert!((system.turbo_gflops_per_watt()
+            - system.turbo_sp_float_fma_gflops() / system.total_tdp_watts() as f64).abs()
+            < 1e-9);
+    }
+
+    #[test]
+    fn power_budget_prefers_chassis_psu_over_per_blade() {
+        let motherboard = test_motherboard(vec![test_processor()], vec![], 0, 1);
+        let system = test_system(vec![test_blade(motherboard)], Some(2000));
+
+        assert_eq!(system.power_budget_watts(), Some(2000));
+    }
+
+    #[test]
+    fn power_budget_sums_standalone_blade_psus_when_chassis_has_none() {
+        let mut blade_a = test_blade(test_motherboard(vec![test_processor()], vec![], 0, 1));
+        blade_a.power_supply_watts = Some(750);
+        let mut blade_b = test_blade(test_motherboard(vec![test_processor()], vec![], 0, 1));
+        blade_b.power_supply_watts = Some(750);
+
+        let system = test_system(vec![blade_a, blade_b], None);
+
+        assert_eq!(system.power_budget_watts(), Some(1500));
+    }
+
+    #[test]
+    fn power_budget_is_none_when_neither_chassis_nor_blades_are_modeled() {
+        let motherboard = test_motherboard(vec![test_processor()], vec![], 0, 1);
+        let system = test_system(vec![test_blade(motherboard)], None);
+
+        assert_eq!(system.power_budget_watts(), None);
+    }
+
+    #[test]
+    fn numa_nodes_apportions_dimms_round_robin_per_socket() {
+        let memory = vec![
+            test_memory(MemoryType::DDR4_2933, 16 * 1024 * 1024 * 1024),
+            test_memory(MemoryType::DDR4_2933, 32 * 1024 * 1024 * 1024),
+            test_memory(MemoryType::DDR4_2933, 8 * 1024 * 1024 * 1024),
+        ];
+        let motherboard = test_motherboard(
+            vec![test_processor(), test_processor()], memory, 4, 1);
+        let system = test_system(vec![test_blade(motherboard)], None);
+
+        let nodes = system.numa_nodes();
+        assert_eq!(nodes.len(), 2);
+        assert_eq!(nodes[0].local_bytes, 16 * 1024 * 1024 * 1024 + 8 * 1024 * 1024 * 1024);
+        assert_eq!(nodes[1].local_bytes, 32 * 1024 * 1024 * 1024);
+    }
+
+    #[test]
+    fn numa_nodes_is_empty_for_a_zero_socket_board() {
+        let motherboard = test_motherboard(vec![], vec![], 0, 1);
+        let system = test_system(vec![test_blade(motherboard)], None);
+
+        assert!(system.numa_nodes().is_empty());
+    }
+
+    #[test]
+    fn numa_balance_reflects_the_lopsided_blade_not_the_average() {
+        // Blade A: two sockets equally loaded (balanced). Blade B: one
+        // socket loaded, one starved (lopsided). The worst blade should
+        // drive the system-wide score down, not an average across blades.
+        let balanced = test_motherboard(
+            vec![test_processor(), test_processor()],
+            vec![
+                test_memory(MemoryType::DDR4_2933, 16 * 1024 * 1024 * 1024),
+                test_memory(MemoryType::DDR4_2933, 16 * 1024 * 1024 * 1024),
+            ], 4, 1);
+        let lopsided = test_motherboard(
+            vec![test_processor(), test_processor()],
+            vec![test_memory(MemoryType::DDR4_2933, 16 * 1024 * 1024 * 1024)],
+            4, 1);
+
+        let system = test_system(
+            vec![test_blade(balanced), test_blade(lopsided)], None);
+
+        assert_eq!(system.numa_balance(), 0.0);
+    }
+
+    #[test]
+    fn numa_balance_is_one_for_a_board_with_no_dimms_installed() {
+        // An unpopulated board has nothing to be lopsided about, so it
+        // shouldn't be penalized the way a starved socket would be.
+        let motherboard = test_motherboard(
+            vec![test_processor(), test_processor()], vec![], 4, 1);
+        let system = test_system(vec![test_blade(motherboard)], None);
+
+        assert_eq!(system.numa_balance(), 1.0);
+    }
+
+    #[test]
+    fn min_local_bandwidth_is_zero_when_a_socket_has_no_local_dimms() {
+        let motherboard = test_motherboard(
+            vec![test_processor(), test_processor()],
+            vec![test_memory(MemoryType::DDR4_2933, 16 * 1024 * 1024 * 1024)],
+            4, 1);
+        let system = test_system(vec![test_blade(motherboard)], None);
+
+        assert_eq!(system.min_local_bandwidth_gbps(), 0.);
+    }
+
+    #[test]
+    fn min_local_bandwidth_is_zero_for_a_zero_socket_board() {
+        let motherboard = test_motherboard(vec![], vec![], 0, 1);
+        let system = test_system(vec![test_blade(motherboard)], None);
+
+        assert_eq!(system.min_local_bandwidth_gbps(), 0.);
+    }
+}
+